@@ -58,6 +58,67 @@ pub enum Table {
     CN,
 }
 
+/// Which table a [`Match`] was resolved from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The character was found in the Traditional Chinese (Taiwan) table.
+    Tw,
+    /// The character was found in the Simplified Chinese table.
+    Cn,
+}
+
+/// The result of a successful [`to_telegraph_detailed`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The resolved telegraph code.
+    pub code: usize,
+    /// Which table the code was resolved from.
+    pub source: Source,
+}
+
+/// Converts a Chinese character to its telegraph code, additionally reporting
+/// which table the match came from.
+///
+/// For [`Table::TW`] and [`Table::CN`] this is simply [`Source::Tw`] or
+/// [`Source::Cn`] respectively. For [`Table::Both`], the TW table is searched
+/// first, then the CN table, so a character present in both tables is always
+/// reported as [`Source::Tw`].
+///
+/// # Arguments
+///
+/// * `character` - A string slice containing exactly one Chinese character
+/// * `table` - Which character table(s) to search
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{to_telegraph_detailed, Match, Source, Table};
+///
+/// assert_eq!(
+///     to_telegraph_detailed("é€™", Table::Both),
+///     Some(Match { code: 6638, source: Source::Tw })
+/// );
+/// assert_eq!(
+///     to_telegraph_detailed("è¿™", Table::Both),
+///     Some(Match { code: 6638, source: Source::Cn })
+/// );
+/// assert_eq!(to_telegraph_detailed("ðŸ¦€", Table::Both), None);
+/// ```
+pub fn to_telegraph_detailed(character: &str, table: Table) -> Option<Match> {
+    match table {
+        Table::Both => to_telegraph_detailed(character, Table::TW)
+            .or_else(|| to_telegraph_detailed(character, Table::CN)),
+        Table::TW => tw::TW_TABLE.get(character).copied().map(|code| Match {
+            code,
+            source: Source::Tw,
+        }),
+        Table::CN => cn::CN_TABLE.get(character).copied().map(|code| Match {
+            code,
+            source: Source::Cn,
+        }),
+    }
+}
+
 /// Converts a Chinese character to its telegraph code.
 ///
 /// # Arguments
@@ -91,14 +152,7 @@ pub enum Table {
 /// assert_eq!(to_telegraph("é€™æ˜¯", Table::Both), None);
 /// ```
 pub fn to_telegraph(character: &str, table: Table) -> Option<usize> {
-    match table {
-        Table::Both => tw::TW_TABLE
-            .get(character)
-            .or_else(|| cn::CN_TABLE.get(character))
-            .copied(),
-        Table::TW => tw::TW_TABLE.get(character).copied(),
-        Table::CN => cn::CN_TABLE.get(character).copied(),
-    }
+    to_telegraph_detailed(character, table).map(|m| m.code)
 }
 #[cfg(feature = "std")]
 extern crate std;
@@ -133,9 +187,394 @@ pub fn to_telegraph_string(character: &str, table: Table) -> Option<std::string:
     to_telegraph(character, table).map(|num| std::format!("{:04}", num))
 }
 
+/// A single unit of output produced by walking a mixed Chinese/non-Chinese string.
+///
+/// See [`to_telegraph_message`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// A Chinese character resolved to its telegraph code, which table it
+    /// came from, and the original matched slice.
+    Code(Match, &'a str),
+    /// A run of input that contained no recognized Chinese character and was
+    /// therefore left untouched.
+    Passthrough(&'a str),
+}
+
+/// An iterator over the [`Token`]s produced by [`to_telegraph_message`].
+///
+/// Chinese characters are resolved to their telegraph code one at a time; runs of
+/// non-Chinese text (ASCII, punctuation, whitespace, unknown characters, ...) are
+/// coalesced into a single borrowed [`Token::Passthrough`].
+pub struct TelegraphTokens<'a> {
+    /// The remaining, not-yet-tokenized suffix of the original input.
+    remainder: &'a str,
+    /// Which table(s) to consult when resolving a character.
+    table: Table,
+}
+
+impl<'a> Iterator for TelegraphTokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.remainder.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut buf = [0u8; 4];
+        if is_chinese(first) {
+            if let Some(m) = to_telegraph_detailed(first.encode_utf8(&mut buf), self.table) {
+                let (matched, rest) = self.remainder.split_at(first.len_utf8());
+                self.remainder = rest;
+                return Some(Token::Code(m, matched));
+            }
+        }
+
+        let mut end = self.remainder.len();
+        for (idx, c) in chars {
+            let mut buf = [0u8; 4];
+            if is_chinese(c) && to_telegraph_detailed(c.encode_utf8(&mut buf), self.table).is_some() {
+                end = idx;
+                break;
+            }
+        }
+        let (passthrough, rest) = self.remainder.split_at(end);
+        self.remainder = rest;
+        Some(Token::Passthrough(passthrough))
+    }
+}
+
+/// Walks `text` character by character, converting each Chinese character to its
+/// telegraph code and leaving everything else (ASCII, punctuation, whitespace, ...)
+/// untouched.
+///
+/// This is the string-level counterpart to [`to_telegraph`], which only accepts a
+/// single character. Non-Han runs are returned as borrowed [`Token::Passthrough`]
+/// slices rather than being copied.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{to_telegraph_message, Match, Source, Table, Token};
+///
+/// let tokens: Vec<_> = to_telegraph_message("ä¸€ is hello", Table::Both).collect();
+/// assert_eq!(
+///     tokens,
+///     vec![
+///         Token::Code(Match { code: 1, source: Source::Tw }, "ä¸€"),
+///         Token::Passthrough(" is hello")
+///     ]
+/// );
+/// ```
+pub fn to_telegraph_message(text: &str, table: Table) -> TelegraphTokens<'_> {
+    TelegraphTokens {
+        remainder: text,
+        table,
+    }
+}
+
+/// Converts `text` to a telegraph message, rendering each resolved code as a
+/// space-separated 4-digit group interleaved with the literal passthrough text,
+/// matching the layout of a real Chinese telegram.
+///
+/// This function is only available when the `std` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{to_telegraph_message_string, Table};
+///
+/// assert_eq!(to_telegraph_message_string("ä¸€", Table::Both), "0001");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_telegraph_message_string(text: &str, table: Table) -> std::string::String {
+    let mut out = std::string::String::new();
+    // Only a run of two or more consecutive codes needs a space between them;
+    // a code next to a passthrough run relies on the passthrough text (e.g. the
+    // space in "ä¸€ is é€™") for separation.
+    let mut prev_was_code = false;
+    for token in to_telegraph_message(text, table) {
+        match token {
+            Token::Code(m, _) => {
+                if prev_was_code {
+                    out.push(' ');
+                }
+                out.push_str(&std::format!("{:04}", m.code));
+                prev_was_code = true;
+            }
+            Token::Passthrough(s) => {
+                out.push_str(s);
+                prev_was_code = false;
+            }
+        }
+    }
+    out
+}
+
+/// The result of a successful [`from_telegraph_detailed`] lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoded {
+    /// The resolved character.
+    pub character: &'static str,
+    /// Which table the character was resolved from.
+    pub source: Source,
+}
+
+/// Converts a telegraph code back to the Chinese character(s) that produce it,
+/// additionally reporting which table the character came from.
+///
+/// For [`Table::Both`], the TW table is searched first, then the CN table, so
+/// a code present in both tables is always reported as [`Source::Tw`],
+/// mirroring [`to_telegraph_detailed`]. Neither `CN_TABLE` nor `TW_TABLE` is
+/// indexed by code, so a single lookup walks the forward table's entries
+/// looking for a match; decoding a whole message should go through
+/// [`from_telegraph_message`] instead, which builds a code-indexed cache once
+/// rather than rescanning per code.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{from_telegraph_detailed, Decoded, Source, Table};
+///
+/// assert_eq!(
+///     from_telegraph_detailed(6638, Table::Both),
+///     Some(Decoded { character: "é€™", source: Source::Tw })
+/// );
+/// assert_eq!(
+///     from_telegraph_detailed(6638, Table::CN),
+///     Some(Decoded { character: "è¿™", source: Source::Cn })
+/// );
+/// assert_eq!(from_telegraph_detailed(9999, Table::Both), None);
+/// ```
+pub fn from_telegraph_detailed(code: usize, table: Table) -> Option<Decoded> {
+    match table {
+        Table::Both => from_telegraph_detailed(code, Table::TW)
+            .or_else(|| from_telegraph_detailed(code, Table::CN)),
+        Table::TW => tw::TW_TABLE
+            .entries()
+            .find(|&(_, &value)| value == code)
+            .map(|(&key, _)| Decoded {
+                character: key,
+                source: Source::Tw,
+            }),
+        Table::CN => cn::CN_TABLE
+            .entries()
+            .find(|&(_, &value)| value == code)
+            .map(|(&key, _)| Decoded {
+                character: key,
+                source: Source::Cn,
+            }),
+    }
+}
+
+/// Converts a telegraph code back to the Chinese character(s) that produce it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{from_telegraph, Table};
+///
+/// assert_eq!(from_telegraph(6638, Table::TW), Some("é€™"));
+/// assert_eq!(from_telegraph(6638, Table::CN), Some("è¿™"));
+/// assert_eq!(from_telegraph(1, Table::Both), Some("ä¸€"));
+/// assert_eq!(from_telegraph(9999, Table::Both), None);
+/// ```
+pub fn from_telegraph(code: usize, table: Table) -> Option<&'static str> {
+    from_telegraph_detailed(code, table).map(|decoded| decoded.character)
+}
+
+/// Decodes a whitespace- or newline-separated stream of 4-digit telegraph code
+/// groups back into Chinese text.
+///
+/// Unlike calling [`from_telegraph`] once per group (which rescans a forward
+/// table for every group), this builds a code-indexed cache of the relevant
+/// table(s) a single time up front, so decoding an `n`-group message costs one
+/// table scan plus `n` cache lookups rather than `n` table scans.
+///
+/// This function is only available when the `std` feature is enabled.
+///
+/// # Errors
+///
+/// Returns `None` if any group is not exactly four ASCII digits, or if a group's
+/// code does not resolve to a character in the specified table(s).
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{from_telegraph_message, Table};
+///
+/// assert_eq!(from_telegraph_message("0001 6638", Table::TW), Some("ä¸€é€™".to_string()));
+/// assert_eq!(from_telegraph_message("0001 66x8", Table::TW), None);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn from_telegraph_message(s: &str, table: Table) -> Option<std::string::String> {
+    let mut reverse = std::collections::HashMap::new();
+    // CN first, then TW overwrites on code collisions, giving TW the same
+    // search priority that `Table::Both` uses elsewhere in this crate.
+    if matches!(table, Table::Both | Table::CN) {
+        reverse.extend(cn::CN_TABLE.entries().map(|(&key, &value)| (value, key)));
+    }
+    if matches!(table, Table::Both | Table::TW) {
+        reverse.extend(tw::TW_TABLE.entries().map(|(&key, &value)| (value, key)));
+    }
+
+    let mut out = std::string::String::new();
+    for group in s.split_whitespace() {
+        if group.len() != 4 || !group.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let code: usize = group.parse().ok()?;
+        out.push_str(reverse.get(&code)?);
+    }
+    Some(out)
+}
+
+/// Converts a Traditional Chinese character to its Simplified counterpart.
+///
+/// A Traditional character and its Simplified counterpart generally share a
+/// single telegraph code, so this resolves `c` to a code in the TW table and
+/// looks that code back up in the CN table. If the code has no distinct entry
+/// in the CN table, `c` is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::simplify;
+///
+/// assert_eq!(simplify("é€™"), Some("è¿™"));
+/// assert_eq!(simplify("ðŸ¦€"), None);
+/// ```
+pub fn simplify(c: &str) -> Option<&str> {
+    let code = to_telegraph(c, Table::TW)?;
+    Some(from_telegraph(code, Table::CN).unwrap_or(c))
+}
+
+/// Converts a Simplified Chinese character to its Traditional counterpart.
+///
+/// The inverse of [`simplify`]: resolves `c` to a code in the CN table and
+/// looks that code back up in the TW table, returning `c` unchanged if the
+/// code has no distinct entry there.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::traditionalize;
+///
+/// assert_eq!(traditionalize("è¿™"), Some("é€™"));
+/// assert_eq!(traditionalize("ðŸ¦€"), None);
+/// ```
+pub fn traditionalize(c: &str) -> Option<&str> {
+    let code = to_telegraph(c, Table::CN)?;
+    Some(from_telegraph(code, Table::TW).unwrap_or(c))
+}
+
+/// Converts every Chinese character in `text` to the script named by `target`,
+/// leaving non-Chinese runs untouched.
+///
+/// `target` selects the output script: [`Table::CN`] simplifies, [`Table::TW`]
+/// traditionalizes. [`Table::Both`] is not a meaningful direction and leaves
+/// `text` unchanged.
+///
+/// This walks `text` with [`to_telegraph_message`] (which already gates each
+/// character on [`is_chinese`]) rather than rescanning a forward table per
+/// character via [`simplify`]/[`traditionalize`], and resolves each
+/// [`Token::Code`] against code-indexed reverse caches of the CN and TW tables
+/// built once up front, so converting an `n`-character message costs two table
+/// scans plus `n` cache lookups rather than `n` table scans.
+///
+/// Each [`Token::Code`] carries both the resolved [`Match`] and the original
+/// matched slice, so a character with no distinct entry in the target table
+/// falls back to exactly what was in the input rather than something
+/// reconstructed from a reverse lookup -- a message mixing Traditional and
+/// Simplified text therefore converts correctly character by character
+/// instead of assuming the whole string reads in one direction.
+///
+/// This function is only available when the `std` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::{convert_message, Table};
+///
+/// assert_eq!(convert_message("é€™æ˜¯ hello", Table::CN), "è¿™æ˜¯ hello");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn convert_message(text: &str, target: Table) -> std::string::String {
+    let cn_reverse: std::collections::HashMap<usize, &'static str> = cn::CN_TABLE
+        .entries()
+        .map(|(&key, &value)| (value, key))
+        .collect();
+    let tw_reverse: std::collections::HashMap<usize, &'static str> = tw::TW_TABLE
+        .entries()
+        .map(|(&key, &value)| (value, key))
+        .collect();
+
+    let mut out = std::string::String::new();
+    for token in to_telegraph_message(text, Table::Both) {
+        match token {
+            Token::Code(Match { code, .. }, matched) => {
+                let converted = match target {
+                    Table::CN => cn_reverse.get(&code).copied(),
+                    Table::TW => tw_reverse.get(&code).copied(),
+                    Table::Both => None,
+                };
+                out.push_str(converted.unwrap_or(matched));
+            }
+            Token::Passthrough(s) => out.push_str(s),
+        }
+    }
+    out
+}
+
+/// Returns `true` if `c` lies within a CJK Unified Ideographs block.
+///
+/// This covers the main block (U+4E00-U+9FFF), Extension A (U+3400-U+4DBF),
+/// and the supplementary Extension B and beyond (U+20000-U+2A6DF,
+/// U+2A700-U+2EBEF, U+30000-U+3134F), giving callers a cheap, allocation-free
+/// way to pre-filter input before paying for a table lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::is_chinese;
+///
+/// assert!(is_chinese('é€™'));
+/// assert!(!is_chinese('a'));
+/// ```
+pub fn is_chinese(c: char) -> bool {
+    matches!(c as u32,
+        0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0x20000..=0x2A6DF
+            | 0x2A700..=0x2EBEF
+            | 0x30000..=0x3134F
+    )
+}
+
+/// Returns `true` if `s` contains at least one character in a CJK Unified
+/// Ideographs block. See [`is_chinese`].
+///
+/// # Examples
+///
+/// ```rust
+/// use chinese_telegraph::contains_chinese;
+///
+/// assert!(contains_chinese("hello é€™"));
+/// assert!(!contains_chinese("hello"));
+/// ```
+pub fn contains_chinese(s: &str) -> bool {
+    s.chars().any(is_chinese)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{to_telegraph, to_telegraph_string};
+    use crate::{
+        contains_chinese, convert_message, from_telegraph, from_telegraph_detailed,
+        from_telegraph_message, is_chinese, simplify, to_telegraph, to_telegraph_detailed,
+        to_telegraph_message, to_telegraph_message_string, to_telegraph_string, traditionalize,
+        Decoded, Match, Source, Table, Token,
+    };
 
     #[test]
     fn it_can_look_up_a_tw_character() {
@@ -172,4 +611,174 @@ mod tests {
         let result = to_telegraph_string("ä¸€", crate::Table::Both);
         assert_eq!(result, Some(std::string::ToString::to_string("0001")));
     }
+
+    #[test]
+    fn it_tokenizes_a_mixed_message() {
+        let tokens: std::vec::Vec<_> =
+            to_telegraph_message("ä¸€ is hello", Table::Both).collect();
+        assert_eq!(
+            tokens,
+            std::vec![
+                Token::Code(
+                    Match {
+                        code: 1,
+                        source: Source::Tw
+                    },
+                    "ä¸€"
+                ),
+                Token::Passthrough(" is hello")
+            ]
+        );
+    }
+
+    #[test]
+    fn it_passes_through_a_message_with_no_chinese() {
+        let tokens: std::vec::Vec<_> =
+            to_telegraph_message("hello, world!", Table::Both).collect();
+        assert_eq!(tokens, std::vec![Token::Passthrough("hello, world!")]);
+    }
+
+    #[test]
+    fn it_renders_a_telegraph_message_string() {
+        let result = to_telegraph_message_string("ä¸€ is é€™", Table::Both);
+        assert_eq!(result, "0001 is 6638");
+    }
+
+    #[test]
+    fn it_decodes_a_tw_code() {
+        let result = from_telegraph(6638, Table::TW);
+        assert_eq!(result, Some("é€™"));
+    }
+
+    #[test]
+    fn it_decodes_a_cn_code() {
+        let result = from_telegraph(6638, Table::CN);
+        assert_eq!(result, Some("è¿™"));
+    }
+
+    #[test]
+    fn it_decodes_a_code_in_both_tables_tw_first() {
+        let result = from_telegraph(6638, Table::Both);
+        assert_eq!(result, Some("é€™"));
+    }
+
+    #[test]
+    fn it_returns_none_for_an_unknown_code() {
+        let result = from_telegraph(9999, Table::Both);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_reports_the_tw_source_when_decoding_in_both_tables() {
+        let result = from_telegraph_detailed(6638, Table::Both);
+        assert_eq!(
+            result,
+            Some(Decoded {
+                character: "é€™",
+                source: Source::Tw
+            })
+        );
+    }
+
+    #[test]
+    fn it_reports_the_cn_source_for_a_single_table_lookup() {
+        let result = from_telegraph_detailed(6638, Table::CN);
+        assert_eq!(
+            result,
+            Some(Decoded {
+                character: "è¿™",
+                source: Source::Cn
+            })
+        );
+    }
+
+    #[test]
+    fn it_returns_none_for_an_unknown_code_when_detailed() {
+        let result = from_telegraph_detailed(9999, Table::Both);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_decodes_a_telegraph_message() {
+        let result = from_telegraph_message("0001 6638", Table::TW);
+        assert_eq!(result, Some(std::string::ToString::to_string("ä¸€é€™")));
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_group() {
+        let result = from_telegraph_message("0001 66x8", Table::TW);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_simplifies_a_traditional_character() {
+        let result = simplify("é€™");
+        assert_eq!(result, Some("è¿™"));
+    }
+
+    #[test]
+    fn it_traditionalizes_a_simplified_character() {
+        let result = traditionalize("è¿™");
+        assert_eq!(result, Some("é€™"));
+    }
+
+    #[test]
+    fn it_returns_none_for_unknown_characters_when_converting() {
+        assert_eq!(simplify("ðŸ¦€"), None);
+        assert_eq!(traditionalize("ðŸ¦€"), None);
+    }
+
+    #[test]
+    fn it_converts_a_message_to_simplified() {
+        let result = convert_message("é€™æ˜¯ hello", Table::CN);
+        assert_eq!(result, "è¿™æ˜¯ hello");
+    }
+
+    #[test]
+    fn it_leaves_a_message_unchanged_for_both() {
+        let result = convert_message("é€™æ˜¯", Table::Both);
+        assert_eq!(result, "é€™æ˜¯");
+    }
+
+    #[test]
+    fn it_identifies_a_chinese_character() {
+        assert!(is_chinese("é€™".chars().next().unwrap()));
+        assert!(!is_chinese('a'));
+    }
+
+    #[test]
+    fn it_checks_a_string_for_chinese_characters() {
+        assert!(contains_chinese("hello é€™"));
+        assert!(!contains_chinese("hello"));
+    }
+
+    #[test]
+    fn it_reports_the_tw_source_for_a_tw_character() {
+        let result = to_telegraph_detailed("é€™", Table::Both);
+        assert_eq!(
+            result,
+            Some(Match {
+                code: 6638,
+                source: Source::Tw
+            })
+        );
+    }
+
+    #[test]
+    fn it_reports_the_cn_source_for_a_cn_only_character() {
+        let result = to_telegraph_detailed("è¿™", Table::Both);
+        assert_eq!(
+            result,
+            Some(Match {
+                code: 6638,
+                source: Source::Cn
+            })
+        );
+    }
+
+    #[test]
+    fn it_returns_none_for_an_unknown_character_when_detailed() {
+        let result = to_telegraph_detailed("ðŸ¦€", Table::Both);
+        assert_eq!(result, None);
+    }
 }